@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::sync::Mutex;
 
@@ -27,6 +28,23 @@ pub struct WindowConfig {
     pub hidden_title: bool,
     #[serde(default = "default_title_bar_style")]
     pub title_bar_style: String,
+    // Explicit logical position; takes precedence over `position` on
+    // whichever axis is set. Unset means "let `position` (or the OS)
+    // decide".
+    #[serde(default)]
+    pub x: Option<f64>,
+    #[serde(default)]
+    pub y: Option<f64>,
+    // Anchor within the target monitor's work area: "center", "top-left",
+    // "top-right", "bottom-left", or "bottom-right". When unset, "center" is
+    // used if `x` or `y` is set; otherwise the window is not repositioned.
+    #[serde(default)]
+    pub position: Option<String>,
+    // Which monitor to position against: a 0-based index ("1"), or a
+    // substring to match against a monitor's name. Unset means "the
+    // window's current monitor".
+    #[serde(default)]
+    pub monitor: Option<String>,
 }
 
 fn default_width() -> f64 {
@@ -77,10 +95,86 @@ impl Default for WindowConfig {
             minimizable: default_minimizable(),
             hidden_title: default_hidden_title(),
             title_bar_style: default_title_bar_style(),
+            x: None,
+            y: None,
+            position: None,
+            monitor: None,
         }
     }
 }
 
+impl WindowConfig {
+    /// Overlay any CLI flags the user passed on top of the YAML/default
+    /// config. `None` means "not passed on the CLI", so the existing value
+    /// is kept.
+    #[allow(clippy::too_many_arguments)]
+    pub fn merge_with_cli_overrides(
+        mut self,
+        width: Option<f64>,
+        height: Option<f64>,
+        resizable: Option<bool>,
+        always_on_top: Option<bool>,
+        skip_taskbar: Option<bool>,
+        focus: Option<bool>,
+        visible_on_all_workspaces: Option<bool>,
+        closable: Option<bool>,
+        minimizable: Option<bool>,
+        hidden_title: Option<bool>,
+        title_bar_style: Option<String>,
+        x: Option<f64>,
+        y: Option<f64>,
+        position: Option<String>,
+        monitor: Option<String>,
+    ) -> Self {
+        if let Some(width) = width {
+            self.width = width;
+        }
+        if let Some(height) = height {
+            self.height = height;
+        }
+        if let Some(resizable) = resizable {
+            self.resizable = resizable;
+        }
+        if let Some(always_on_top) = always_on_top {
+            self.always_on_top = always_on_top;
+        }
+        if let Some(skip_taskbar) = skip_taskbar {
+            self.skip_taskbar = skip_taskbar;
+        }
+        if let Some(focus) = focus {
+            self.focus = focus;
+        }
+        if let Some(visible_on_all_workspaces) = visible_on_all_workspaces {
+            self.visible_on_all_workspaces = visible_on_all_workspaces;
+        }
+        if let Some(closable) = closable {
+            self.closable = closable;
+        }
+        if let Some(minimizable) = minimizable {
+            self.minimizable = minimizable;
+        }
+        if let Some(hidden_title) = hidden_title {
+            self.hidden_title = hidden_title;
+        }
+        if let Some(title_bar_style) = title_bar_style {
+            self.title_bar_style = title_bar_style;
+        }
+        if x.is_some() {
+            self.x = x;
+        }
+        if y.is_some() {
+            self.y = y;
+        }
+        if position.is_some() {
+            self.position = position;
+        }
+        if monitor.is_some() {
+            self.monitor = monitor;
+        }
+        self
+    }
+}
+
 // Hardcoded window configuration for notification template
 impl WindowConfig {
     pub fn notification_template() -> Self {
@@ -96,6 +190,10 @@ impl WindowConfig {
             minimizable: false,
             hidden_title: true,
             title_bar_style: "overlay".to_string(),
+            x: None,
+            y: None,
+            position: None,
+            monitor: None,
         }
     }
 }
@@ -107,6 +205,24 @@ pub struct AppConfig {
     pub notification: Option<NotificationConfig>,
     #[serde(default)]
     pub custom: Option<CustomConfig>,
+    // A config can define several popups at once via `windows`, instead of
+    // the single top-level `notification`/`custom` section above. Each
+    // entry is built into its own window, labeled `popup-0`, `popup-1`,
+    // etc. If both are set, `windows` wins and the top-level section is
+    // ignored (with a warning).
+    #[serde(default)]
+    pub windows: Vec<WindowEntryConfig>,
+    // Accelerator -> action, e.g. `"CmdOrCtrl+Shift+X": { action: dismiss }`.
+    #[serde(default)]
+    pub shortcuts: HashMap<String, ShortcutAction>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct WindowEntryConfig {
+    #[serde(default)]
+    pub notification: Option<NotificationConfig>,
+    #[serde(default)]
+    pub custom: Option<CustomConfig>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -132,17 +248,128 @@ pub struct CustomConfig {
     pub title: Option<String>,
     #[serde(default)]
     pub window: Option<WindowConfig>,
+    // Remote pages loaded via `url` cannot call `get_config`/`exit_with_code`
+    // by default, since they're untrusted; set this to opt back in.
+    #[serde(default)]
+    pub allow_remote_ipc: bool,
+    // Extra HTTP headers (e.g. `Authorization`, `Cookie`) attached to every
+    // request the webview makes to `url`'s origin. Lets internal pages load
+    // behind a bearer token without embedding credentials in the URL.
+    #[serde(default)]
+    pub headers: Option<HashMap<String, String>>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct WebhookConfig {
     pub url: String,
     pub payload: String,
+    #[serde(default = "default_webhook_method")]
+    pub method: String,
+    #[serde(default)]
+    pub headers: Option<HashMap<String, String>>,
+}
+
+fn default_webhook_method() -> String {
+    "POST".to_string()
 }
 
-// Internal config representation (used after CLI/YAML parsing)
+#[derive(Debug, Clone, Serialize)]
+pub struct WebhookOutcome {
+    pub status: Option<u16>,
+    pub error: Option<String>,
+}
+
+impl WebhookConfig {
+    /// Substitute `{button}` in the configured payload with which button
+    /// triggered the call ("primary" or "secondary").
+    pub fn render_payload(&self, button: &str) -> String {
+        self.payload.replace("{button}", button)
+    }
+
+    /// Perform the configured HTTP call, returning its status code or the
+    /// error that prevented a response from coming back. Bounded by a
+    /// timeout so a hung endpoint can't stall exit-code delivery (this runs
+    /// synchronously from the IPC command and the global-shortcut handler).
+    pub fn send(&self, button: &str) -> WebhookOutcome {
+        let method = reqwest::Method::from_bytes(self.method.as_bytes()).unwrap_or_else(|_| {
+            eprintln!("Unknown webhook method '{}', using POST", self.method);
+            reqwest::Method::POST
+        });
+
+        let client = match reqwest::blocking::Client::builder()
+            .timeout(std::time::Duration::from_secs(10))
+            .build()
+        {
+            Ok(client) => client,
+            Err(e) => {
+                return WebhookOutcome {
+                    status: None,
+                    error: Some(e.to_string()),
+                };
+            }
+        };
+
+        let mut request = client.request(method, &self.url).body(self.render_payload(button));
+
+        if let Some(headers) = &self.headers {
+            for (name, value) in headers {
+                request = request.header(name, value);
+            }
+        }
+
+        match request.send() {
+            Ok(response) => WebhookOutcome {
+                status: Some(response.status().as_u16()),
+                error: None,
+            },
+            Err(e) => WebhookOutcome {
+                status: None,
+                error: Some(e.to_string()),
+            },
+        }
+    }
+}
+
+// An action fired when its paired accelerator is pressed. `Dismiss` exits
+// the process directly; the `Trigger*` actions run the same path as
+// clicking the corresponding notification button (fire its webhook, then
+// exit with the outcome's code).
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum ShortcutAction {
+    Dismiss {
+        #[serde(default = "default_dismiss_code")]
+        code: i32,
+    },
+    TriggerPrimary,
+    TriggerSecondary,
+}
+
+fn default_dismiss_code() -> i32 {
+    EXIT_DISMISSED
+}
+
+// Internal config representation (used after CLI/YAML parsing).
+// `windows` holds one entry per popup to spawn; single-popup configs (CLI
+// flags, or a YAML file with just `notification`/`custom`) produce a
+// one-element vec.
 #[derive(Debug, Clone, Serialize)]
 pub struct Config {
+    pub windows: Vec<ConfigEntry>,
+    pub shortcuts: HashMap<String, ShortcutAction>,
+}
+
+pub fn default_shortcuts() -> HashMap<String, ShortcutAction> {
+    HashMap::from([(
+        "CmdOrCtrl+Shift+X".to_string(),
+        ShortcutAction::Dismiss {
+            code: EXIT_DISMISSED,
+        },
+    )])
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigEntry {
     pub content: Content,
     pub window: WindowConfig,
 }
@@ -150,14 +377,16 @@ pub struct Config {
 #[derive(Debug, Clone, Serialize)]
 #[serde(tag = "type", rename_all = "lowercase")]
 pub enum Content {
-    Custom(CustomContent),
+    Webview(WebviewContent),
     Notification(NotificationContent),
 }
 
 #[derive(Debug, Clone, Serialize)]
-pub struct CustomContent {
+pub struct WebviewContent {
     pub url: String,
     pub window_title: Option<String>,
+    pub allow_remote_ipc: bool,
+    pub headers: Option<HashMap<String, String>>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -171,11 +400,12 @@ pub struct NotificationContent {
     pub button_secondary_webhook: Option<WebhookConfig>,
 }
 
-// Convert from AppConfig (YAML) to internal Config
-impl AppConfig {
-    pub fn to_config(self) -> Result<Config, String> {
+// Convert a single `notification`/`custom` section (from the top level of
+// an AppConfig, or from one entry of its `windows` list) into a ConfigEntry.
+impl WindowEntryConfig {
+    fn to_entry(self) -> Result<ConfigEntry, String> {
         if let Some(notification) = self.notification {
-            return Ok(Config {
+            return Ok(ConfigEntry {
                 content: Content::Notification(NotificationContent {
                     title: notification.title,
                     description: notification.description,
@@ -190,10 +420,12 @@ impl AppConfig {
         }
 
         if let Some(custom) = self.custom {
-            return Ok(Config {
-                content: Content::Custom(CustomContent {
+            return Ok(ConfigEntry {
+                content: Content::Webview(WebviewContent {
                     url: custom.url,
                     window_title: custom.title,
+                    allow_remote_ipc: custom.allow_remote_ipc,
+                    headers: custom.headers,
                 }),
                 window: custom.window.unwrap_or_default(),
             });
@@ -203,6 +435,85 @@ impl AppConfig {
     }
 }
 
+// Convert from AppConfig (YAML) to internal Config
+impl AppConfig {
+    pub fn to_config(self) -> Result<Config, String> {
+        let shortcuts = if self.shortcuts.is_empty() {
+            default_shortcuts()
+        } else {
+            self.shortcuts
+        };
+
+        if !self.windows.is_empty() {
+            if self.notification.is_some() || self.custom.is_some() {
+                eprintln!(
+                    "Warning: top-level 'notification'/'custom' section is ignored because 'windows' is set"
+                );
+            }
+            let windows = self
+                .windows
+                .into_iter()
+                .map(WindowEntryConfig::to_entry)
+                .collect::<Result<Vec<_>, _>>()?;
+            return Ok(Config { windows, shortcuts });
+        }
+
+        let entry = WindowEntryConfig {
+            notification: self.notification,
+            custom: self.custom,
+        }
+        .to_entry()?;
+
+        Ok(Config {
+            windows: vec![entry],
+            shortcuts,
+        })
+    }
+}
+
+// Process exit codes so a caller invoking `popup` in a pipeline can branch
+// on what the user chose.
+pub const EXIT_PRIMARY: i32 = 0;
+pub const EXIT_SECONDARY: i32 = 1;
+pub const EXIT_DISMISSED: i32 = 2;
+pub const EXIT_WEBHOOK_FAILURE: i32 = 3;
+
+impl Config {
+    /// Look up the config entry for a window label of the form `popup-N`.
+    pub fn entry_for_label(&self, label: &str) -> Option<&ConfigEntry> {
+        label
+            .strip_prefix("popup-")
+            .and_then(|index| index.parse::<usize>().ok())
+            .and_then(|index| self.windows.get(index))
+    }
+
+    /// Label of the first notification window, used to target
+    /// `trigger_primary`/`trigger_secondary` shortcuts when several popups
+    /// are open at once.
+    pub fn first_notification_label(&self) -> Option<String> {
+        self.windows
+            .iter()
+            .enumerate()
+            .find(|(_, entry)| matches!(entry.content, Content::Notification(_)))
+            .map(|(index, _)| format!("popup-{}", index))
+    }
+}
+
+impl ConfigEntry {
+    /// Whether IPC commands (`get_config`, `exit_with_code`, ...) should be
+    /// callable from this window's content when it's loaded from a remote
+    /// http(s) origin. The bundled notification UI is not served from a
+    /// remote origin, so this check does not apply to it; a notification
+    /// window on a remote origin is denied. Webview content is denied unless
+    /// it opts in via `allow_remote_ipc`.
+    pub fn allows_remote_ipc(&self) -> bool {
+        match &self.content {
+            Content::Webview(webview) => webview.allow_remote_ipc,
+            Content::Notification(_) => false,
+        }
+    }
+}
+
 pub struct AppState {
     pub config: Mutex<Option<Config>>,
 }
@@ -2,8 +2,10 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 use clap::Parser;
+use serde::Serialize;
 use std::sync::Mutex;
-use tauri::State;
+use tauri::{Emitter, Manager, State};
+use tauri_plugin_global_shortcut::GlobalShortcutExt;
 
 #[derive(Parser, Debug)]
 #[command(name = "popup")]
@@ -112,25 +114,376 @@ struct Args {
     #[arg(long)]
     title_bar_style: Option<String>,
 
+    /// Explicit window x position, in logical pixels from the target
+    /// monitor's origin.
+    #[arg(long)]
+    x: Option<f64>,
+
+    /// Explicit window y position, in logical pixels from the target
+    /// monitor's origin.
+    #[arg(long)]
+    y: Option<f64>,
+
+    /// Anchor the window within its monitor's work area: "center",
+    /// "top-left", "top-right", "bottom-left", or "bottom-right".
+    #[arg(long)]
+    position: Option<String>,
+
+    /// Which monitor to position the window against: a 0-based index ("1"),
+    /// or a substring to match against a monitor's name. Defaults to the
+    /// window's current monitor.
+    #[arg(long)]
+    monitor: Option<String>,
+
+    /// Allow the loaded webview content to call IPC commands (get_config,
+    /// exit_with_code) even when it's a remote http(s) URL. Off by default.
+    #[arg(long)]
+    allow_remote_ipc: bool,
+
+    /// Extra header to attach to webview requests, as "Name: Value". Can be
+    /// passed multiple times.
+    #[arg(long = "header", value_name = "NAME: VALUE")]
+    headers: Vec<String>,
+
+    /// Bind a global accelerator to an action, as "ACCELERATOR=ACTION"
+    /// (action is one of "dismiss", "dismiss:<code>", "trigger_primary", or
+    /// "trigger_secondary"). Can be passed multiple times.
+    #[arg(long = "shortcut", value_name = "ACCELERATOR=ACTION")]
+    shortcuts: Vec<String>,
+
     /// List available content types
     #[arg(long)]
     templates: bool,
 }
 
-#[tauri::command]
-fn get_config(state: State<popup_lib::AppState>) -> Result<popup_lib::Config, String> {
+// The bundled notification UI is served over http(s) too (e.g.
+// `https://tauri.localhost` on Windows, since there's no custom-scheme
+// support there), so scheme alone can't tell it apart from a genuinely
+// remote `Content::Webview` target. Only treat the known bundled-asset host
+// as local; anything else on http(s) is remote. If the webview's URL can't
+// be determined at all, fail closed and treat it as remote/untrusted.
+fn is_remote_url(url: Option<url::Url>) -> bool {
+    url.map(|url| {
+        let is_bundled_host = url.host_str() == Some("tauri.localhost");
+        matches!(url.scheme(), "http" | "https") && !is_bundled_host
+    })
+    .unwrap_or(true)
+}
+
+// IPC commands are refused to content on a remote http(s) origin unless its
+// window explicitly opted in via `allow_remote_ipc`. The bundled app UI and
+// other non-http(s) content (e.g. `file://`) are allowed. A
+// compromised/malicious remote page loaded via `Content::Webview` otherwise
+// has no access to `get_config`/`exit_with_code`.
+fn check_ipc_allowed(
+    state: &State<popup_lib::AppState>,
+    webview: &tauri::Webview,
+) -> Result<(), String> {
+    if !is_remote_url(webview.url().ok()) {
+        return Ok(());
+    }
+
     let config = state.config.lock().unwrap();
-    match config.as_ref() {
-        Some(cfg) => Ok(cfg.clone()),
-        None => Err("No config loaded".to_string()),
+    let allowed = config
+        .as_ref()
+        .and_then(|config| config.entry_for_label(webview.label()))
+        .map(|entry| entry.allows_remote_ipc())
+        .unwrap_or(false);
+
+    if allowed {
+        Ok(())
+    } else {
+        Err("IPC calls from remote webview content are not allowed".to_string())
     }
 }
 
 #[tauri::command]
-fn exit_with_code(code: i32) {
+fn get_config(
+    webview: tauri::Webview,
+    state: State<popup_lib::AppState>,
+    label: Option<String>,
+) -> Result<popup_lib::ConfigEntry, String> {
+    check_ipc_allowed(&state, &webview)?;
+
+    // A window may only ever fetch its own config slice — otherwise any
+    // caller permitted to invoke IPC at all (the bundled notification UI,
+    // or a webview that opted in via `allow_remote_ipc`) could pass another
+    // window's label and read its secrets (webhook URLs/payloads, injected
+    // headers).
+    if let Some(requested) = label {
+        if requested != webview.label() {
+            return Err("Cannot fetch another window's config".to_string());
+        }
+    }
+
+    let config = state.config.lock().unwrap();
+    let config = config.as_ref().ok_or("No config loaded")?;
+
+    config
+        .entry_for_label(webview.label())
+        .cloned()
+        .ok_or_else(|| format!("No config for window '{}'", webview.label()))
+}
+
+#[tauri::command]
+fn exit_with_code(
+    webview: tauri::Webview,
+    state: State<popup_lib::AppState>,
+    code: i32,
+) -> Result<(), String> {
+    check_ipc_allowed(&state, &webview)?;
     std::process::exit(code);
 }
 
+#[derive(Serialize, Clone)]
+struct OpenedEvent {
+    label: String,
+}
+
+#[derive(Serialize, Clone)]
+struct ButtonEvent {
+    label: String,
+    button: String,
+}
+
+#[derive(Serialize, Clone)]
+struct WebhookResultEvent {
+    label: String,
+    button: String,
+    success: bool,
+    status: Option<u16>,
+    error: Option<String>,
+}
+
+/// Fire the webhook (if any) bound to `button` on the window identified by
+/// `label`, emit `popup://button` (and `popup://webhook-result` when a
+/// webhook was fired), then exit with the outcome's exit code. Shared by the `button_pressed` command
+/// (mouse clicks) and the global-shortcut trigger actions (keyboard).
+fn fire_button_action(app: &tauri::AppHandle, label: &str, button: &str) {
+    let _ = app.emit(
+        "popup://button",
+        ButtonEvent {
+            label: label.to_string(),
+            button: button.to_string(),
+        },
+    );
+
+    let webhook = {
+        let state = app.state::<popup_lib::AppState>();
+        let config = state.config.lock().unwrap();
+        config
+            .as_ref()
+            .and_then(|config| config.entry_for_label(label))
+            .and_then(|entry| match &entry.content {
+                popup_lib::Content::Notification(notification) => match button {
+                    "primary" => notification.button_primary_webhook.clone(),
+                    _ => notification.button_secondary_webhook.clone(),
+                },
+                popup_lib::Content::Webview(_) => None,
+            })
+    };
+
+    let base_exit_code = if button == "primary" {
+        popup_lib::EXIT_PRIMARY
+    } else {
+        popup_lib::EXIT_SECONDARY
+    };
+
+    let exit_code = match webhook {
+        Some(webhook) => {
+            let outcome = webhook.send(button);
+            let success = outcome.error.is_none()
+                && outcome.status.is_some_and(|status| (200..300).contains(&status));
+            let _ = app.emit(
+                "popup://webhook-result",
+                WebhookResultEvent {
+                    label: label.to_string(),
+                    button: button.to_string(),
+                    success,
+                    status: outcome.status,
+                    error: outcome.error,
+                },
+            );
+            if success {
+                base_exit_code
+            } else {
+                popup_lib::EXIT_WEBHOOK_FAILURE
+            }
+        }
+        None => base_exit_code,
+    };
+
+    app.exit(exit_code);
+}
+
+#[tauri::command]
+fn button_pressed(
+    webview: tauri::Webview,
+    app: tauri::AppHandle,
+    state: State<popup_lib::AppState>,
+    button: String,
+) -> Result<(), String> {
+    check_ipc_allowed(&state, &webview)?;
+    fire_button_action(&app, webview.label(), &button);
+    Ok(())
+}
+
+// Resolve `window_config.monitor` against the window's available monitors:
+// a 0-based index, or a substring match against a monitor's name. Falls
+// back to the window's current monitor when unset or unmatched.
+fn select_monitor(
+    window: &tauri::WebviewWindow,
+    window_config: &popup_lib::WindowConfig,
+) -> Option<tauri::monitor::Monitor> {
+    if let Some(selector) = window_config.monitor.as_deref() {
+        let monitors = window.available_monitors().ok()?;
+
+        if let Ok(index) = selector.parse::<usize>() {
+            if let Some(monitor) = monitors.get(index) {
+                return Some(monitor.clone());
+            }
+            eprintln!(
+                "Monitor index {} out of range ({} monitor(s) available), using current monitor",
+                index,
+                monitors.len()
+            );
+        } else if let Some(monitor) = monitors
+            .iter()
+            .find(|m| m.name().is_some_and(|name| name.contains(selector)))
+        {
+            return Some(monitor.clone());
+        } else {
+            eprintln!(
+                "No monitor matching '{}', using current monitor",
+                selector
+            );
+        }
+    }
+
+    window.current_monitor().ok().flatten()
+}
+
+// Resolve `window_config`'s x/y/position into a physical on-screen
+// position, anchored against the target monitor's work area and clamped so
+// the window stays inside it. A window larger than the work area is pinned
+// to the area's top-left corner and overflows to the right/bottom.
+fn resolve_window_position(
+    window: &tauri::WebviewWindow,
+    window_config: &popup_lib::WindowConfig,
+) -> Option<tauri::PhysicalPosition<i32>> {
+    if window_config.x.is_none() && window_config.y.is_none() && window_config.position.is_none() {
+        return None;
+    }
+
+    let Some(monitor) = select_monitor(window, window_config) else {
+        eprintln!("Failed to resolve a monitor to position the window against; leaving it at its default position");
+        return None;
+    };
+    let monitor_position = monitor.position();
+    let scale_factor = monitor.scale_factor();
+    let work_area = monitor.work_area();
+    let area_position = work_area.position;
+    let area_size = work_area.size;
+    let window_size = window.outer_size().ok()?;
+
+    let min_x = area_position.x;
+    let min_y = area_position.y;
+    let max_x = (area_position.x + area_size.width as i32 - window_size.width as i32).max(min_x);
+    let max_y = (area_position.y + area_size.height as i32 - window_size.height as i32).max(min_y);
+    let center_x = area_position.x + (area_size.width as i32 - window_size.width as i32) / 2;
+    let center_y = area_position.y + (area_size.height as i32 - window_size.height as i32) / 2;
+
+    let (anchor_x, anchor_y) = match window_config.position.as_deref() {
+        Some("top-left") => (min_x, min_y),
+        Some("top-right") => (max_x, min_y),
+        Some("bottom-left") => (min_x, max_y),
+        Some("bottom-right") => (max_x, max_y),
+        Some("center") | None => (center_x, center_y),
+        Some(other) => {
+            eprintln!("Unknown window position anchor: {}, using center", other);
+            (center_x, center_y)
+        }
+    };
+
+    let x = window_config
+        .x
+        .map(|x| monitor_position.x + (x * scale_factor) as i32)
+        .unwrap_or(anchor_x);
+    let y = window_config
+        .y
+        .map(|y| monitor_position.y + (y * scale_factor) as i32)
+        .unwrap_or(anchor_y);
+
+    Some(tauri::PhysicalPosition::new(
+        x.clamp(min_x, max_x),
+        y.clamp(min_y, max_y),
+    ))
+}
+
+// Parse a "--shortcut ACCELERATOR=ACTION" flag into its accelerator string
+// and ShortcutAction.
+fn parse_shortcut_flag(raw: &str) -> Result<(String, popup_lib::ShortcutAction), String> {
+    let (accelerator, action) = raw
+        .split_once('=')
+        .ok_or_else(|| format!("Invalid --shortcut '{}', expected ACCELERATOR=ACTION", raw))?;
+
+    let action = match action {
+        "dismiss" => popup_lib::ShortcutAction::Dismiss {
+            code: popup_lib::EXIT_DISMISSED,
+        },
+        "trigger_primary" => popup_lib::ShortcutAction::TriggerPrimary,
+        "trigger_secondary" => popup_lib::ShortcutAction::TriggerSecondary,
+        other => match other.strip_prefix("dismiss:") {
+            Some(code) => {
+                let code = code
+                    .parse::<i32>()
+                    .map_err(|_| format!("Invalid dismiss code in --shortcut '{}'", raw))?;
+                popup_lib::ShortcutAction::Dismiss { code }
+            }
+            None => {
+                return Err(format!(
+                    "Unknown shortcut action '{}' in --shortcut '{}'",
+                    other, raw
+                ));
+            }
+        },
+    };
+
+    Ok((accelerator.to_string(), action))
+}
+
+// Carry out the action bound to a pressed accelerator. `Dismiss` exits the
+// process directly; the trigger actions run the exact same path a mouse
+// click on that button would (fire its webhook, emit the outcome, exit).
+fn handle_shortcut_action(app: &tauri::AppHandle, action: &popup_lib::ShortcutAction) {
+    match action {
+        popup_lib::ShortcutAction::Dismiss { code } => {
+            app.exit(*code);
+        }
+        popup_lib::ShortcutAction::TriggerPrimary => trigger_button_via_shortcut(app, "primary"),
+        popup_lib::ShortcutAction::TriggerSecondary => {
+            trigger_button_via_shortcut(app, "secondary")
+        }
+    }
+}
+
+// With several popups open at once, a keyboard shortcut targets the first
+// notification window rather than a specific one the user picked.
+fn trigger_button_via_shortcut(app: &tauri::AppHandle, button: &str) {
+    let label = {
+        let state = app.state::<popup_lib::AppState>();
+        let config = state.config.lock().unwrap();
+        config
+            .as_ref()
+            .and_then(|config| config.first_notification_label())
+    };
+
+    match label {
+        Some(label) => fire_button_action(app, &label, button),
+        None => eprintln!("No notification window open to trigger '{}' on", button),
+    }
+}
+
 fn main() {
     let args = Args::parse();
 
@@ -210,9 +563,22 @@ fn main() {
                         })
                         .unwrap();
 
+                    let headers: std::collections::HashMap<String, String> = args
+                        .headers
+                        .iter()
+                        .filter_map(|header| header.split_once(':'))
+                        .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+                        .collect();
+
                     popup_lib::Content::Webview(popup_lib::WebviewContent {
                         url,
                         window_title: args.title.clone(),
+                        allow_remote_ipc: args.allow_remote_ipc,
+                        headers: if headers.is_empty() {
+                            None
+                        } else {
+                            Some(headers)
+                        },
                     })
                 }
                 Some("notification") => {
@@ -240,7 +606,12 @@ fn main() {
                         args.button_primary_webhook_payload.clone(),
                     ) {
                         (Some(url), Some(payload)) => {
-                            Some(popup_lib::WebhookConfig { url, payload })
+                            Some(popup_lib::WebhookConfig {
+                                url,
+                                payload,
+                                method: "POST".to_string(),
+                                headers: None,
+                            })
                         }
                         (Some(_), None) => {
                             eprintln!("Error: --button-primary-webhook-payload required when --button-primary-webhook-url is provided");
@@ -254,7 +625,12 @@ fn main() {
                         args.button_secondary_webhook_payload.clone(),
                     ) {
                         (Some(url), Some(payload)) => {
-                            Some(popup_lib::WebhookConfig { url, payload })
+                            Some(popup_lib::WebhookConfig {
+                                url,
+                                payload,
+                                method: "POST".to_string(),
+                                headers: None,
+                            })
                         }
                         (Some(_), None) => {
                             eprintln!("Error: --button-secondary-webhook-payload required when --button-secondary-webhook-url is provided");
@@ -287,21 +663,49 @@ fn main() {
             };
 
             popup_lib::Config {
-                content,
-                window: None,
+                windows: vec![popup_lib::ConfigEntry {
+                    content,
+                    window: popup_lib::WindowConfig::default(),
+                }],
+                shortcuts: popup_lib::default_shortcuts(),
             }
         }
     };
 
-    // Build and run the application
+    // --shortcut flags add to (or override) whatever shortcuts the config
+    // file defined.
+    let mut config = config;
+    for raw in &args.shortcuts {
+        match parse_shortcut_flag(raw) {
+            Ok((accelerator, action)) => {
+                config.shortcuts.insert(accelerator, action);
+            }
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    // Build and run the application. Shortcuts are registered individually
+    // in `setup` below so a single bad accelerator doesn't abort startup;
+    // the handler here just dispatches whichever one fired to its action.
+    let shortcuts = config.shortcuts.clone();
     let shortcut_plugin = tauri_plugin_global_shortcut::Builder::new()
-        .with_shortcut("CmdOrCtrl+Shift+X")
-        .unwrap_or_else(|e| {
-            eprintln!("Failed to register global shortcut: {}", e);
-            std::process::exit(1);
-        })
-        .with_handler(|app, _shortcut, _event| {
-            app.exit(0);
+        .with_handler(move |app, shortcut, event| {
+            if event.state() != tauri_plugin_global_shortcut::ShortcutState::Pressed {
+                return;
+            }
+            // Match by parsed shortcut, not by string: the fired shortcut's
+            // string form is normalized and differs from the configured
+            // accelerator text (e.g. "CmdOrCtrl+Shift+X").
+            if let Some((_, action)) = shortcuts.iter().find(|(accelerator, _)| {
+                accelerator
+                    .parse::<tauri_plugin_global_shortcut::Shortcut>()
+                    .is_ok_and(|parsed| parsed.id() == shortcut.id())
+            }) {
+                handle_shortcut_action(app, action);
+            }
         })
         .build();
 
@@ -312,95 +716,184 @@ fn main() {
         .manage(popup_lib::AppState {
             config: Mutex::new(Some(config.clone())),
         })
-        .invoke_handler(tauri::generate_handler![get_config, exit_with_code])
+        .invoke_handler(tauri::generate_handler![
+            get_config,
+            exit_with_code,
+            button_pressed
+        ])
         .setup(move |app| {
             // Set macOS activation policy to hide from dock (Accessory allows windows)
             #[cfg(target_os = "macos")]
             app.set_activation_policy(tauri::ActivationPolicy::Accessory);
 
-            // Get window config from YAML or use defaults, then merge with CLI overrides
-            let mut window_config = config_clone
-                .window
-                .as_ref()
-                .cloned()
-                .unwrap_or_default()
-                .merge_with_cli_overrides(
-                    args.width,
-                    args.height,
-                    args.resizable,
-                    args.always_on_top,
-                    args.skip_taskbar,
-                    args.focus,
-                    args.visible_on_all_workspaces,
-                    args.closable,
-                    args.minimizable,
-                    args.hidden_title,
-                    args.title_bar_style,
-                );
-
-            // Determine webview URL and window title based on content type
-            let (webview_url, window_title) = match &config_clone.content {
-                popup_lib::Content::Webview(webview) => {
-                    // Load external URL directly
-                    let url =
-                        tauri::WebviewUrl::External(webview.url.parse().unwrap_or_else(|e| {
-                            eprintln!("Error: Failed to parse webview URL: {}", e);
-                            std::process::exit(1);
-                        }));
-                    let title = webview
-                        .window_title
-                        .clone()
-                        .unwrap_or_else(|| "Popup".to_string());
-                    (url, title)
-                }
-                popup_lib::Content::Notification(notification) => {
-                    // Apply notification template overrides (template wins)
-                    window_config.width = 500.0;
-                    window_config.height = 300.0;
-                    window_config.resizable = false;
-                    window_config.skip_taskbar = true;
-
-                    // Load React app for notification UI
-                    (tauri::WebviewUrl::default(), notification.title.clone())
+            // Register each configured shortcut independently so a single
+            // invalid/unavailable accelerator doesn't take down the others.
+            for accelerator in config_clone.shortcuts.keys() {
+                if let Err(e) = app.global_shortcut().register(accelerator.as_str()) {
+                    eprintln!("Failed to register shortcut '{}': {}", accelerator, e);
                 }
-            };
-
-            // Create the window programmatically with config values
-            let window_builder = tauri::WebviewWindowBuilder::new(app, "main", webview_url)
-                .title(&window_title)
-                .inner_size(window_config.width, window_config.height)
-                .resizable(window_config.resizable)
-                .always_on_top(window_config.always_on_top)
-                .skip_taskbar(window_config.skip_taskbar)
-                .focused(window_config.focus)
-                .visible_on_all_workspaces(window_config.visible_on_all_workspaces)
-                .closable(window_config.closable)
-                .minimizable(window_config.minimizable);
-
-            // Apply title bar style
-            let window_builder = if window_config.hidden_title {
-                window_builder.hidden_title(true)
-            } else {
-                window_builder
-            };
+            }
 
-            let window_builder = match window_config.title_bar_style.as_str() {
-                "overlay" => window_builder.title_bar_style(tauri::TitleBarStyle::Overlay),
-                "transparent" => window_builder.title_bar_style(tauri::TitleBarStyle::Transparent),
-                "visible" => window_builder.title_bar_style(tauri::TitleBarStyle::Visible),
-                _ => {
-                    eprintln!(
-                        "Unknown title bar style: {}, using overlay",
-                        window_config.title_bar_style
+            // Build one window per config entry, each with its own unique
+            // label so its webview can later fetch just its own slice of
+            // state via `get_config(label)`.
+            for (index, entry) in config_clone.windows.iter().enumerate() {
+                let label = format!("popup-{}", index);
+
+                // Get window config from YAML or use defaults, then merge with CLI overrides
+                let mut window_config = entry
+                    .window
+                    .clone()
+                    .merge_with_cli_overrides(
+                        args.width,
+                        args.height,
+                        args.resizable,
+                        args.always_on_top,
+                        args.skip_taskbar,
+                        args.focus,
+                        args.visible_on_all_workspaces,
+                        args.closable,
+                        args.minimizable,
+                        args.hidden_title,
+                        args.title_bar_style.clone(),
+                        args.x,
+                        args.y,
+                        args.position.clone(),
+                        args.monitor.clone(),
                     );
-                    window_builder.title_bar_style(tauri::TitleBarStyle::Overlay)
+
+                // Extra headers (e.g. Authorization) to attach to every
+                // request the webview makes to its own origin.
+                let webview_headers = match &entry.content {
+                    popup_lib::Content::Webview(webview) => webview
+                        .headers
+                        .clone()
+                        .map(|headers| (webview.url.clone(), headers)),
+                    popup_lib::Content::Notification(_) => None,
+                };
+
+                // Determine webview URL and window title based on content type
+                let (webview_url, window_title) = match &entry.content {
+                    popup_lib::Content::Webview(webview) => {
+                        // Load external URL directly
+                        let url = tauri::WebviewUrl::External(
+                            webview.url.parse().unwrap_or_else(|e| {
+                                eprintln!("Error: Failed to parse webview URL: {}", e);
+                                std::process::exit(1);
+                            }),
+                        );
+                        let title = webview
+                            .window_title
+                            .clone()
+                            .unwrap_or_else(|| "Popup".to_string());
+                        (url, title)
+                    }
+                    popup_lib::Content::Notification(notification) => {
+                        // Apply notification template overrides (template wins)
+                        window_config.width = 500.0;
+                        window_config.height = 300.0;
+                        window_config.resizable = false;
+                        window_config.skip_taskbar = true;
+
+                        // Load React app for notification UI
+                        (tauri::WebviewUrl::default(), notification.title.clone())
+                    }
+                };
+
+                // Create the window programmatically with config values
+                let window_builder = tauri::WebviewWindowBuilder::new(app, &label, webview_url)
+                    .title(&window_title)
+                    .inner_size(window_config.width, window_config.height)
+                    .resizable(window_config.resizable)
+                    .always_on_top(window_config.always_on_top)
+                    .skip_taskbar(window_config.skip_taskbar)
+                    .focused(window_config.focus)
+                    .visible_on_all_workspaces(window_config.visible_on_all_workspaces)
+                    .closable(window_config.closable)
+                    .minimizable(window_config.minimizable);
+
+                // Attach configured headers to requests for this window's
+                // own origin (e.g. an auth token for an internal dashboard).
+                let window_builder = match webview_headers {
+                    Some((origin, headers)) => {
+                        // Compare scheme + host + port, not just host: a
+                        // same-host request over plain http instead of the
+                        // configured https must not get these headers
+                        // attached, or they'd be sent in the clear.
+                        let origin_triple = origin.parse::<url::Url>().ok().map(|url| {
+                            (
+                                url.scheme().to_string(),
+                                url.host_str().map(str::to_string),
+                                url.port_or_known_default(),
+                            )
+                        });
+                        window_builder.on_web_resource_request(move |request, _response| {
+                            let is_own_origin = origin_triple.as_ref().is_some_and(
+                                |(scheme, host, port)| {
+                                    let uri = request.uri();
+                                    let request_port = uri.port_u16().or_else(|| {
+                                        match uri.scheme_str() {
+                                            Some("http") => Some(80),
+                                            Some("https") => Some(443),
+                                            _ => None,
+                                        }
+                                    });
+                                    uri.scheme_str() == Some(scheme.as_str())
+                                        && uri.host().map(str::to_string) == *host
+                                        && request_port == *port
+                                },
+                            );
+                            if !is_own_origin {
+                                return;
+                            }
+                            for (key, value) in &headers {
+                                if let (Ok(name), Ok(value)) = (
+                                    tauri::http::HeaderName::from_bytes(key.as_bytes()),
+                                    tauri::http::HeaderValue::from_str(value),
+                                ) {
+                                    request.headers_mut().insert(name, value);
+                                }
+                            }
+                        })
+                    }
+                    None => window_builder,
+                };
+
+                // Apply title bar style
+                let window_builder = if window_config.hidden_title {
+                    window_builder.hidden_title(true)
+                } else {
+                    window_builder
+                };
+
+                let window_builder = match window_config.title_bar_style.as_str() {
+                    "overlay" => window_builder.title_bar_style(tauri::TitleBarStyle::Overlay),
+                    "transparent" => {
+                        window_builder.title_bar_style(tauri::TitleBarStyle::Transparent)
+                    }
+                    "visible" => window_builder.title_bar_style(tauri::TitleBarStyle::Visible),
+                    _ => {
+                        eprintln!(
+                            "Unknown title bar style: {}, using overlay",
+                            window_config.title_bar_style
+                        );
+                        window_builder.title_bar_style(tauri::TitleBarStyle::Overlay)
+                    }
+                };
+
+                let window = window_builder.build().map_err(|e| {
+                    eprintln!("Failed to create window '{}': {}", label, e);
+                    e
+                })?;
+
+                if let Some(position) = resolve_window_position(&window, &window_config) {
+                    if let Err(e) = window.set_position(tauri::Position::Physical(position)) {
+                        eprintln!("Failed to position window '{}': {}", label, e);
+                    }
                 }
-            };
 
-            window_builder.build().map_err(|e| {
-                eprintln!("Failed to create window: {}", e);
-                e
-            })?;
+                let _ = app.emit("popup://opened", OpenedEvent { label });
+            }
 
             Ok(())
         })